@@ -25,7 +25,7 @@ fn main() {
         data: "data".as_bytes().to_vec(),
     };
 
-    let signers = &[&payer];
+    let signers: &[&dyn Signer] = &[&payer];
     // set up accounts
     let accounts = vec![
         AccountMeta::new(blob_account, false),
@@ -40,5 +40,7 @@ fn main() {
         instruction_name,
         instruction_data,
         accounts,
+        None,
+        None,
     ).unwrap();
 }
@@ -50,5 +50,7 @@ fn main() {
         &table_pk,
         &payer,
         accounts,
+        None,
+        None,
     ).unwrap();
 }
@@ -0,0 +1,188 @@
+#[allow(unused_imports)]
+pub use borsh::BorshDeserialize;
+pub use solana_client::rpc_client::RpcClient;
+#[allow(unused_imports)]
+pub use solana_sdk::pubkey::Pubkey;
+
+use crate::legacy_tx::get_discriminant;
+
+/// Number of bytes Anchor prepends to an account's data as its type discriminant.
+const ANCHOR_DISCRIMINANT_LEN: usize = 8;
+
+/// fetch an account's raw, undecoded data.
+///
+/// This is the building block `fetch_anchor_account` is built on; use it
+/// directly for accounts that are not Anchor/Borsh-encoded.
+pub fn fetch_account_raw(
+    connection: &RpcClient,
+    pubkey: &Pubkey,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    Ok(connection.get_account(pubkey)?.data)
+}
+
+/// fetch and deserialize an Anchor program account.
+///
+/// Strips the leading 8-byte Anchor discriminant and deserializes the
+/// remainder with Borsh into `T`. This closes the read half of the
+/// client's CRUD story: callers who can write to an Anchor account with
+/// `signed_call` can now read it back without dropping down to raw
+/// `get_account`.
+///
+/// # Examples
+///
+/// This example uses the [`solana_program_client`] crate.
+///
+/// ```
+/// use solana_program_client::account::*;
+///
+/// #[derive(BorshDeserialize)]
+/// pub struct Blob {
+///     pub data: Vec<u8>,
+/// }
+///
+/// fn main() {
+///     let connection = RpcClient::new("https://api.devnet.solana.com");
+///     let program_id = blob::ID;
+///     let (blob_account, _) = Pubkey::find_program_address(&[&b"blob"[..]], &program_id);
+///
+///     let blob: Blob = fetch_anchor_account(&connection, &blob_account).unwrap();
+/// }
+/// ```
+pub fn fetch_anchor_account<T>(
+    connection: &RpcClient,
+    pubkey: &Pubkey,
+) -> Result<T, Box<dyn std::error::Error>>
+where
+    T: BorshDeserialize,
+{
+    decode_anchor_account(&fetch_account_raw(connection, pubkey)?)
+}
+
+/// fetch and deserialize an Anchor program account, validating its discriminant.
+///
+/// Same as [`fetch_anchor_account`], but first recomputes the expected
+/// discriminant for `account_name` with the same `hash(...)[..8]` scheme
+/// `get_discriminant` uses for instructions (namespace `"account"`), and
+/// returns an error if the account's stored discriminant doesn't match.
+pub fn fetch_anchor_account_checked<T>(
+    connection: &RpcClient,
+    pubkey: &Pubkey,
+    account_name: &str,
+) -> Result<T, Box<dyn std::error::Error>>
+where
+    T: BorshDeserialize,
+{
+    decode_anchor_account_checked(&fetch_account_raw(connection, pubkey)?, account_name)
+}
+
+/// strip the leading 8-byte Anchor discriminant from `data` and deserialize the rest.
+///
+/// Pure helper behind [`fetch_anchor_account`], split out so the
+/// too-short-discriminant error path can be unit tested without a live
+/// RPC connection.
+fn decode_anchor_account<T>(data: &[u8]) -> Result<T, Box<dyn std::error::Error>>
+where
+    T: BorshDeserialize,
+{
+    if data.len() < ANCHOR_DISCRIMINANT_LEN {
+        return Err("account data is shorter than the Anchor discriminant".into());
+    }
+
+    Ok(T::try_from_slice(&data[ANCHOR_DISCRIMINANT_LEN..])?)
+}
+
+/// same as [`decode_anchor_account`], but validates the discriminant against `account_name` first.
+///
+/// Pure helper behind [`fetch_anchor_account_checked`], split out so the
+/// too-short and mismatched-discriminant error paths can be unit tested
+/// without a live RPC connection.
+fn decode_anchor_account_checked<T>(
+    data: &[u8],
+    account_name: &str,
+) -> Result<T, Box<dyn std::error::Error>>
+where
+    T: BorshDeserialize,
+{
+    if data.len() < ANCHOR_DISCRIMINANT_LEN {
+        return Err("account data is shorter than the Anchor discriminant".into());
+    }
+
+    let expected_discriminant = get_discriminant("account", account_name);
+    if data[..ANCHOR_DISCRIMINANT_LEN] != expected_discriminant {
+        return Err(format!(
+            "account discriminant mismatch: expected {account_name}"
+        )
+        .into());
+    }
+
+    Ok(T::try_from_slice(&data[ANCHOR_DISCRIMINANT_LEN..])?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use borsh::BorshSerialize;
+
+    #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+    pub struct Blob {
+        pub data: Vec<u8>,
+    }
+
+    fn encode_anchor_account(account_name: &str, blob: &Blob) -> Vec<u8> {
+        let mut data = get_discriminant("account", account_name).to_vec();
+        data.extend(blob.try_to_vec().unwrap());
+        data
+    }
+
+    #[test]
+    fn test_decode_anchor_account_strips_discriminant() {
+        let blob = Blob {
+            data: "data".as_bytes().to_vec(),
+        };
+        let encoded = encode_anchor_account("Blob", &blob);
+
+        let decoded: Blob = decode_anchor_account(&encoded).unwrap();
+
+        assert_eq!(decoded, blob);
+    }
+
+    #[test]
+    fn test_decode_anchor_account_rejects_short_data() {
+        let err = decode_anchor_account::<Blob>(&[0u8; ANCHOR_DISCRIMINANT_LEN - 1]).unwrap_err();
+
+        assert!(err.to_string().contains("shorter than the Anchor discriminant"));
+    }
+
+    #[test]
+    fn test_decode_anchor_account_checked_accepts_matching_discriminant() {
+        let blob = Blob {
+            data: "data".as_bytes().to_vec(),
+        };
+        let encoded = encode_anchor_account("Blob", &blob);
+
+        let decoded: Blob = decode_anchor_account_checked(&encoded, "Blob").unwrap();
+
+        assert_eq!(decoded, blob);
+    }
+
+    #[test]
+    fn test_decode_anchor_account_checked_rejects_mismatched_discriminant() {
+        let blob = Blob {
+            data: "data".as_bytes().to_vec(),
+        };
+        let encoded = encode_anchor_account("Blob", &blob);
+
+        let err = decode_anchor_account_checked::<Blob>(&encoded, "OtherAccount").unwrap_err();
+
+        assert!(err.to_string().contains("account discriminant mismatch"));
+    }
+
+    #[test]
+    fn test_decode_anchor_account_checked_rejects_short_data() {
+        let err =
+            decode_anchor_account_checked::<Blob>(&[0u8; ANCHOR_DISCRIMINANT_LEN - 1], "Blob")
+                .unwrap_err();
+
+        assert!(err.to_string().contains("shorter than the Anchor discriminant"));
+    }
+}
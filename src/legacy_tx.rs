@@ -5,6 +5,7 @@ pub use solana_client::rpc_client::RpcClient;
 #[allow(unused_imports)]
 pub use solana_sdk::{
     commitment_config::CommitmentConfig,
+    compute_budget::ComputeBudgetInstruction,
     instruction::{AccountMeta, Instruction},
     message::Message,
     pubkey::Pubkey,
@@ -20,6 +21,12 @@ pub use solana_sdk::{
 /// This method fully signs a transaction with all required signers, which
 /// must be present in the `keypairs` slice.
 ///
+/// `compute_unit_limit` and `compute_unit_price` are optional. When set,
+/// the corresponding `ComputeBudgetInstruction` is prepended to the
+/// instruction vector (limit first, then price), ahead of the program
+/// instruction, so callers can land transactions during congestion
+/// without hand-building the budget instructions themselves.
+///
 /// # Panics
 ///
 /// Panics when signing or signature verification fails.
@@ -53,8 +60,8 @@ pub use solana_sdk::{
 ///         data: "data".as_bytes().to_vec(),
 ///     };
 
-///     // setup signers
-///     let signers = &[&payer];
+///     // setup signers; any `Signer` works here, including hardware wallets
+///     let signers: &[&dyn Signer] = &[&payer];
 ///
 ///     // set up accounts
 ///     let accounts = vec![
@@ -71,6 +78,8 @@ pub use solana_sdk::{
 ///         instruction_name,
 ///         instruction_data,
 ///         accounts,
+///         None,
+///         None,
 ///     )
 ///     .unwrap();
 /// }
@@ -78,11 +87,13 @@ pub use solana_sdk::{
 pub fn signed_call<T>(
     connection: &RpcClient,
     program_id: &Pubkey,
-    payer: &Keypair,
-    signers: &[&Keypair],
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
     instruction_name: &str,
     instruction_data: T,
     accounts: Vec<AccountMeta>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
 ) -> Result<Signature, Box<dyn std::error::Error>>
 where
     T: BorshSerialize,
@@ -97,11 +108,14 @@ where
         accounts.clone(),
     );
 
+    // prepend compute budget instructions, if requested, ahead of the program instruction
+    let instructions = prepend_compute_budget(ix, compute_unit_limit, compute_unit_price);
+
     // get latest block hash
     let blockhash = connection.get_latest_blockhash()?;
 
     // construct message
-    let msg = Message::new_with_blockhash(&[ix], Some(&payer.pubkey()), &blockhash);
+    let msg = Message::new_with_blockhash(&instructions, Some(&payer.pubkey()), &blockhash);
 
     //construct transaction
     let mut tx = Transaction::new_unsigned(msg);
@@ -115,6 +129,26 @@ where
     Ok(tx_signature)
 }
 
+/// prepend compute-budget instructions ahead of `ix`, if requested.
+///
+/// Order is `set_compute_unit_limit`, then `set_compute_unit_price`, then
+/// `ix`, so congestion controls always precede the program instruction.
+pub(crate) fn prepend_compute_budget(
+    ix: Instruction,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+) -> Vec<Instruction> {
+    let mut instructions = Vec::with_capacity(3);
+    if let Some(limit) = compute_unit_limit {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+    }
+    if let Some(price) = compute_unit_price {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+    }
+    instructions.push(ix);
+    instructions
+}
+
 /// returns function signature
 ///
 /// accepts name space and name function
@@ -156,7 +190,7 @@ mod test {
             data: "another data".as_bytes().to_vec(),
         };
 
-        let signers = &[&payer];
+        let signers: &[&dyn Signer] = &[&payer];
         // set up accounts
         let accounts = vec![
             AccountMeta::new(blob_account, false),
@@ -171,8 +205,39 @@ mod test {
             instruction_name,
             instruction_data,
             accounts,
+            None,
+            None,
         );
 
         assert!(tx_signature.is_ok());
     }
+
+    #[test]
+    fn test_prepend_compute_budget_orders_limit_then_price_then_ix() {
+        let program_id = Pubkey::new_unique();
+        let ix = Instruction::new_with_bytes(program_id, &[], vec![]);
+
+        let instructions = prepend_compute_budget(ix.clone(), Some(200_000), Some(1));
+
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000)
+        );
+        assert_eq!(
+            instructions[1],
+            ComputeBudgetInstruction::set_compute_unit_price(1)
+        );
+        assert_eq!(instructions[2], ix);
+    }
+
+    #[test]
+    fn test_prepend_compute_budget_is_noop_when_unset() {
+        let program_id = Pubkey::new_unique();
+        let ix = Instruction::new_with_bytes(program_id, &[], vec![]);
+
+        let instructions = prepend_compute_budget(ix.clone(), None, None);
+
+        assert_eq!(instructions, vec![ix]);
+    }
 }
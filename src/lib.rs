@@ -0,0 +1,6 @@
+pub mod account;
+pub mod legacy_tx;
+pub mod nonblocking;
+pub mod nonce;
+pub mod tx_builder;
+pub mod versioned_tx;
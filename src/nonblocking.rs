@@ -0,0 +1,261 @@
+use std::str::FromStr;
+
+use base64::{engine::general_purpose, Engine as _};
+#[allow(unused_imports)]
+pub use borsh::{BorshDeserialize, BorshSerialize};
+pub use solana_address_lookup_table_program::state::AddressLookupTable;
+pub use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::{rpc_config::RpcSendTransactionConfig, rpc_request::RpcRequest};
+#[allow(unused_imports)]
+pub use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    commitment_config::{CommitmentConfig, CommitmentLevel},
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    message::{Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::Signature,
+    signature::{Keypair, Signer},
+    signer::EncodableKey,
+    transaction::{Transaction, VersionedTransaction},
+};
+pub use solana_transaction_status::UiTransactionEncoding;
+
+use crate::legacy_tx::{get_discriminant, prepend_compute_budget};
+
+/// Async mirror of [`crate::legacy_tx::signed_call`], built on the
+/// [`nonblocking`][solana_client::nonblocking] RPC client so it can be
+/// awaited from Tokio-based services instead of blocking the executor.
+///
+/// `payer` and `signers` are bound as `Signer + Sync` rather than plain
+/// `Signer`: a bare `&dyn Signer` is not `Send`, and these values are held
+/// across the `.await` points below, which would make the returned future
+/// `!Send` and unusable with `tokio::spawn`.
+///
+/// # Panics
+///
+/// Panics when signing or signature verification fails.
+pub async fn signed_call<T>(
+    connection: &RpcClient,
+    program_id: &Pubkey,
+    payer: &(dyn Signer + Sync),
+    signers: &[&(dyn Signer + Sync)],
+    instruction_name: &str,
+    instruction_data: T,
+    accounts: Vec<AccountMeta>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+) -> Result<Signature, Box<dyn std::error::Error>>
+where
+    T: BorshSerialize,
+{
+    // get discriminant
+    let instruction_discriminant = get_discriminant("global", instruction_name);
+
+    // construct instruction
+    let ix = Instruction::new_with_borsh(
+        *program_id,
+        &(instruction_discriminant, instruction_data),
+        accounts.clone(),
+    );
+
+    // prepend compute budget instructions, if requested, ahead of the program instruction
+    let instructions = prepend_compute_budget(ix, compute_unit_limit, compute_unit_price);
+
+    // get latest block hash
+    let blockhash = connection.get_latest_blockhash().await?;
+
+    // construct message
+    let msg = Message::new_with_blockhash(&instructions, Some(&payer.pubkey()), &blockhash);
+
+    //construct transaction
+    let mut tx = Transaction::new_unsigned(msg);
+
+    // sign transaction; drop the `+ Sync` bound back to `&dyn Signer` to satisfy
+    // `Signers`'s impl for `[&dyn Signer]`, inside a block so the converted signers
+    // are dropped before the next `.await`
+    {
+        let signers: Vec<&dyn Signer> = signers.iter().map(|s| *s as &dyn Signer).collect();
+        tx.sign(&signers, tx.message.recent_blockhash);
+    }
+
+    // send and confirm transaction
+    let tx_signature = connection.send_and_confirm_transaction(&tx).await?;
+
+    Ok(tx_signature)
+}
+
+/// Async mirror of [`crate::versioned_tx::call_with_lookup_table`].
+///
+/// `payer` and `signers` are bound as `Signer + Sync` for the same reason
+/// as in [`signed_call`]: they are held across `.await` points, and a
+/// bare `&dyn Signer` would make the returned future `!Send`.
+///
+/// # Panics
+///
+/// Panics when signing or signature verification fails.
+pub async fn call_with_lookup_table<T>(
+    connection: &RpcClient,
+    program_id: &Pubkey,
+    instruction_name: &str,
+    instruction_data: T,
+    lookup_table_key: &Pubkey,
+    payer: &(dyn Signer + Sync),
+    signers: &[&(dyn Signer + Sync)],
+    accounts: Vec<AccountMeta>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+) -> Result<Signature, Box<dyn std::error::Error>>
+where
+    T: BorshSerialize,
+{
+    // get lookup table addresses from lookup table key
+    let lookup_table_account = connection.get_account(lookup_table_key).await?;
+    let address_lookup_table = AddressLookupTable::deserialize(&lookup_table_account.data)?;
+    let address_lookup_table_account = AddressLookupTableAccount {
+        key: *lookup_table_key,
+        addresses: address_lookup_table.addresses.to_vec(),
+    };
+
+    // construct instruction
+    let instruction_discriminant = get_discriminant("global", instruction_name);
+    let ix = Instruction::new_with_borsh(
+        *program_id,
+        &(instruction_discriminant, instruction_data),
+        accounts,
+    );
+
+    // prepend compute budget instructions, if requested, ahead of the program instruction
+    let instructions = prepend_compute_budget(ix, compute_unit_limit, compute_unit_price);
+
+    // create versioned transaction with lookup table
+    let blockhash = connection.get_latest_blockhash().await?;
+    // drop the `+ Sync` bound back to `&dyn Signer` to satisfy `Signers`'s impl for
+    // `[&dyn Signer]`, inside a block so the converted signers are dropped before the
+    // next `.await`
+    let tx = {
+        let signers: Vec<&dyn Signer> = signers.iter().map(|s| *s as &dyn Signer).collect();
+        VersionedTransaction::try_new(
+            VersionedMessage::V0(solana_sdk::message::v0::Message::try_compile(
+                &payer.pubkey(),
+                &instructions,
+                &[address_lookup_table_account],
+                blockhash,
+            )?),
+            &signers,
+        )?
+    };
+
+    // serialize and encode transaction
+    let serialized_tx = bincode::serialize(&tx)?;
+    let serialized_encoded_tx = general_purpose::STANDARD.encode(serialized_tx);
+
+    // construct transaction pre-execution configuration
+    let config = RpcSendTransactionConfig {
+        skip_preflight: false,
+        preflight_commitment: Some(CommitmentLevel::Confirmed),
+        encoding: Some(UiTransactionEncoding::Base64),
+        ..RpcSendTransactionConfig::default()
+    };
+
+    // submit transaction and retrieve transaction signature
+    let signature = connection
+        .send::<String>(
+            RpcRequest::SendTransaction,
+            serde_json::json!([serialized_encoded_tx, config]),
+        )
+        .await?;
+
+    // verify transaction execution
+    connection
+        .confirm_transaction_with_commitment(
+            &Signature::from_str(signature.as_str())?,
+            CommitmentConfig::finalized(),
+        )
+        .await?;
+
+    Ok(Signature::from_str(&signature)?)
+}
+
+/// Async mirror of [`crate::versioned_tx::create_lookup_table`].
+///
+/// `payer` is bound as `Signer + Sync` for the same reason as in
+/// [`signed_call`]: it is held across an `.await` point, and a bare
+/// `&dyn Signer` would make the returned future `!Send`.
+///
+/// # Panics
+///
+/// Panics when signature verification fails.
+pub async fn create_lookup_table(
+    connection: &RpcClient,
+    payer: &(dyn Signer + Sync),
+    latest_blockhash: solana_sdk::hash::Hash,
+) -> Result<Pubkey, Box<dyn std::error::Error>> {
+    let recent_slot = connection.get_slot().await?;
+    let (create_ix, table_pk) =
+        solana_address_lookup_table_program::instruction::create_lookup_table(
+            payer.pubkey(),
+            payer.pubkey(),
+            recent_slot,
+        );
+
+    // drop the `+ Sync` bound back to `&dyn Signer` to satisfy `Signers`'s impl for
+    // `[&dyn Signer]`, building the transaction in its own statement so the converted
+    // signer is dropped before the `.await`
+    let tx = {
+        let payer: &dyn Signer = payer;
+        Transaction::new_signed_with_payer(
+            &[create_ix],
+            Some(&payer.pubkey()),
+            &[payer],
+            latest_blockhash,
+        )
+    };
+    connection.send_and_confirm_transaction(&tx).await?;
+
+    Ok(table_pk)
+}
+
+/// Async mirror of [`crate::versioned_tx::extend_lookup_table`].
+///
+/// `payer` is bound as `Signer + Sync` for the same reason as in
+/// [`signed_call`]: it is held across an `.await` point, and a bare
+/// `&dyn Signer` would make the returned future `!Send`.
+///
+/// # Panics
+///
+/// Panics when signature verification fails.
+pub async fn extend_lookup_table(
+    connection: &RpcClient,
+    payer: &(dyn Signer + Sync),
+    latest_blockhash: solana_sdk::hash::Hash,
+    table_pk: Pubkey,
+    new_accounts: Vec<Pubkey>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    // add accounts to look up table
+    let extend_ix = solana_address_lookup_table_program::instruction::extend_lookup_table(
+        table_pk,
+        payer.pubkey(),
+        Some(payer.pubkey()),
+        new_accounts,
+    );
+
+    // drop the `+ Sync` bound back to `&dyn Signer` to satisfy `Signers`'s impl for
+    // `[&dyn Signer]`, building the transaction in its own statement so the converted
+    // signer is dropped before the `.await`
+    let tx = {
+        let payer: &dyn Signer = payer;
+        Transaction::new_signed_with_payer(
+            &[extend_ix],
+            Some(&payer.pubkey()),
+            &[payer],
+            latest_blockhash,
+        )
+    };
+    let signature = connection.send_and_confirm_transaction(&tx).await?;
+
+    Ok(connection
+        .confirm_transaction_with_commitment(&signature, CommitmentConfig::confirmed())
+        .await
+        .is_ok())
+}
@@ -0,0 +1,193 @@
+#[allow(unused_imports)]
+pub use borsh::BorshSerialize;
+pub use solana_client::rpc_client::RpcClient;
+#[allow(unused_imports)]
+pub use solana_sdk::{
+    account_utils::StateMut,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    nonce::state::{State, Versions},
+    pubkey::Pubkey,
+    signature::Signature,
+    signature::{Keypair, Signer},
+    signer::EncodableKey,
+    system_instruction,
+    transaction::Transaction,
+};
+
+use crate::legacy_tx::{get_discriminant, prepend_compute_budget};
+
+/// Create a durable nonce account, funded by `payer` and authorized by `nonce_authority`.
+///
+/// Returns the nonce account's public key. The account is rent-exempt and
+/// its stored blockhash can later be used in place of a recent blockhash by
+/// [`signed_call_with_nonce`], letting a transaction be signed offline and
+/// submitted at any later time until the nonce is advanced or consumed.
+///
+/// # Panics
+///
+/// Panics when signature verification fails.
+pub fn create_nonce_account(
+    connection: &RpcClient,
+    payer: &dyn Signer,
+    nonce_account: &dyn Signer,
+    nonce_authority: &Pubkey,
+) -> Result<Pubkey, Box<dyn std::error::Error>> {
+    let rent = connection.get_minimum_balance_for_rent_exemption(State::size())?;
+
+    let create_ixs = system_instruction::create_nonce_account(
+        &payer.pubkey(),
+        &nonce_account.pubkey(),
+        nonce_authority,
+        rent,
+    );
+
+    let blockhash = connection.get_latest_blockhash()?;
+    let tx = Transaction::new_signed_with_payer(
+        &create_ixs,
+        Some(&payer.pubkey()),
+        &[payer, nonce_account],
+        blockhash,
+    );
+    connection.send_and_confirm_transaction(&tx)?;
+
+    Ok(nonce_account.pubkey())
+}
+
+/// build the instruction vector for a durable-nonce transaction.
+///
+/// Pure helper behind [`signed_call_with_nonce`], split out so the one
+/// invariant the request calls out - `advance_nonce_account` must be
+/// instruction index 0 - can be unit tested without a live RPC connection.
+fn build_nonce_instructions<T>(
+    program_id: &Pubkey,
+    nonce_pubkey: &Pubkey,
+    nonce_authority: &Pubkey,
+    instruction_name: &str,
+    instruction_data: T,
+    accounts: Vec<AccountMeta>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+) -> Vec<Instruction>
+where
+    T: BorshSerialize,
+{
+    let instruction_discriminant = get_discriminant("global", instruction_name);
+    let ix = Instruction::new_with_borsh(
+        *program_id,
+        &(instruction_discriminant, instruction_data),
+        accounts,
+    );
+
+    // advance-nonce must be instruction index 0, so it is pushed first, ahead of
+    // the compute-budget instructions (if any) that `prepend_compute_budget` prepends to `ix`
+    let mut instructions = vec![system_instruction::advance_nonce_account(
+        nonce_pubkey,
+        nonce_authority,
+    )];
+    instructions.extend(prepend_compute_budget(
+        ix,
+        compute_unit_limit,
+        compute_unit_price,
+    ));
+
+    instructions
+}
+
+/// Sign and submit a legacy transaction using a durable nonce instead of a recent blockhash.
+///
+/// This fetches the nonce account's stored blockhash and uses it as the
+/// transaction's `recent_blockhash`, which never expires the usual ~150
+/// blocks a live blockhash does. The critical invariant enforced here is
+/// that `system_instruction::advance_nonce_account` is instruction index 0:
+/// the runtime only accepts a durable-nonce transaction when the advance
+/// instruction is the very first one, so compute-budget and program
+/// instructions are appended after it.
+///
+/// # Panics
+///
+/// Panics when signing or signature verification fails.
+pub fn signed_call_with_nonce<T>(
+    connection: &RpcClient,
+    program_id: &Pubkey,
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
+    nonce_pubkey: &Pubkey,
+    nonce_authority: &Pubkey,
+    instruction_name: &str,
+    instruction_data: T,
+    accounts: Vec<AccountMeta>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+) -> Result<Signature, Box<dyn std::error::Error>>
+where
+    T: BorshSerialize,
+{
+    // fetch the nonce account and read its stored blockhash
+    let nonce_account = connection.get_account(nonce_pubkey)?;
+    let nonce_data = match StateMut::<Versions>::state(&nonce_account)?.convert_to_current() {
+        State::Initialized(data) => data,
+        State::Uninitialized => return Err("nonce account is not initialized".into()),
+    };
+    let nonce_hash = nonce_data.blockhash();
+
+    let instructions = build_nonce_instructions(
+        program_id,
+        nonce_pubkey,
+        nonce_authority,
+        instruction_name,
+        instruction_data,
+        accounts,
+        compute_unit_limit,
+        compute_unit_price,
+    );
+
+    // construct message using the nonce's stored blockhash in place of a recent blockhash
+    let msg = Message::new_with_blockhash(&instructions, Some(&payer.pubkey()), &nonce_hash);
+
+    let mut tx = Transaction::new_unsigned(msg);
+    tx.sign(signers, tx.message.recent_blockhash);
+
+    let tx_signature = connection.send_and_confirm_transaction(&tx)?;
+
+    Ok(tx_signature)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(BorshSerialize)]
+    pub struct UpdateBlob {
+        pub data: Vec<u8>,
+    }
+
+    #[test]
+    fn test_advance_nonce_is_first_instruction() {
+        let program_id = Pubkey::new_unique();
+        let nonce_pubkey = Pubkey::new_unique();
+        let nonce_authority = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+
+        let instructions = build_nonce_instructions(
+            &program_id,
+            &nonce_pubkey,
+            &nonce_authority,
+            "update_blob",
+            UpdateBlob {
+                data: "data".as_bytes().to_vec(),
+            },
+            vec![AccountMeta::new(account, false)],
+            Some(200_000),
+            Some(1),
+        );
+
+        let expected_advance_ix =
+            system_instruction::advance_nonce_account(&nonce_pubkey, &nonce_authority);
+
+        assert_eq!(instructions[0], expected_advance_ix);
+        // advance-nonce, compute-unit limit, compute-unit price, program instruction
+        assert_eq!(instructions.len(), 4);
+    }
+}
@@ -0,0 +1,277 @@
+use std::str::FromStr;
+
+use base64::{engine::general_purpose, Engine as _};
+#[allow(unused_imports)]
+pub use borsh::BorshSerialize;
+pub use solana_client::rpc_client::RpcClient;
+use solana_client::{rpc_config::RpcSendTransactionConfig, rpc_request::RpcRequest};
+#[allow(unused_imports)]
+pub use solana_sdk::{
+    address_lookup_table::AddressLookupTableAccount,
+    commitment_config::{CommitmentConfig, CommitmentLevel},
+    compute_budget::ComputeBudgetInstruction,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::{Signature, Signer},
+    transaction::{Transaction, VersionedTransaction},
+};
+use solana_address_lookup_table_program::state::AddressLookupTable;
+use solana_transaction_status::UiTransactionEncoding;
+
+use crate::legacy_tx::{get_discriminant, prepend_compute_budget};
+
+/// Builds a single transaction out of several instructions, committed atomically.
+///
+/// `signed_call` and `call_with_lookup_table` each submit exactly one program
+/// instruction; `TransactionBuilder` composes an arbitrary sequence (e.g.
+/// create PDA + initialize + update) and decides at send time whether to
+/// compile a legacy [`Message`] or a [`VersionedMessage::V0`], based on
+/// whether any lookup tables were supplied.
+///
+/// # Examples
+///
+/// This example uses the [`solana_program_client`] crate.
+///
+/// ```
+/// use solana_program_client::tx_builder::*;
+/// use solana_sdk::signature::Keypair;
+///
+/// #[derive(BorshSerialize)]
+/// pub struct CreateBlob {}
+///
+/// #[derive(BorshSerialize)]
+/// pub struct UpdateBlob {
+///     pub data: Vec<u8>,
+/// }
+///
+/// fn main() {
+///     let connection = RpcClient::new("https://api.devnet.solana.com");
+///     let program_id = Pubkey::new_unique();
+///     let payer = Keypair::new();
+///     let signers: &[&dyn Signer] = &[&payer];
+///
+///     // get blob PDA
+///     let (blob_account, _) = Pubkey::find_program_address(&[&b"blob"[..]], &program_id);
+///     let create_accounts = vec![
+///         AccountMeta::new(blob_account, false),
+///         AccountMeta::new(payer.pubkey(), true),
+///     ];
+///     let update_accounts = create_accounts.clone();
+///
+///     let _tx_signature = TransactionBuilder::new()
+///         .add_anchor_instruction(&program_id, "create_blob", CreateBlob {}, create_accounts)
+///         .add_anchor_instruction(
+///             &program_id,
+///             "update_blob",
+///             UpdateBlob {
+///                 data: "data".as_bytes().to_vec(),
+///             },
+///             update_accounts,
+///         )
+///         .with_compute_budget(Some(200_000), Some(1))
+///         .build_and_send(&connection, &payer, signers)
+///         .unwrap();
+/// }
+/// ```
+#[derive(Default)]
+pub struct TransactionBuilder {
+    instructions: Vec<Instruction>,
+    lookup_tables: Vec<Pubkey>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
+}
+
+impl TransactionBuilder {
+    /// create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// append an Anchor-style instruction, using the same discriminant scheme as `signed_call`.
+    pub fn add_anchor_instruction<T>(
+        mut self,
+        program_id: &Pubkey,
+        instruction_name: &str,
+        instruction_data: T,
+        accounts: Vec<AccountMeta>,
+    ) -> Self
+    where
+        T: BorshSerialize,
+    {
+        let instruction_discriminant = get_discriminant("global", instruction_name);
+        let ix = Instruction::new_with_borsh(
+            *program_id,
+            &(instruction_discriminant, instruction_data),
+            accounts,
+        );
+        self.instructions.push(ix);
+        self
+    }
+
+    /// append a raw, already-constructed instruction.
+    pub fn add_raw_instruction(mut self, instruction: Instruction) -> Self {
+        self.instructions.push(instruction);
+        self
+    }
+
+    /// set the address lookup tables to compile the transaction with.
+    ///
+    /// Supplying a non-empty list switches `build_and_send` from a legacy
+    /// `Message` to a `VersionedMessage::V0`.
+    pub fn with_lookup_tables(mut self, lookup_tables: Vec<Pubkey>) -> Self {
+        self.lookup_tables = lookup_tables;
+        self
+    }
+
+    /// set an optional compute-unit limit and price.
+    ///
+    /// When present, the corresponding `ComputeBudgetInstruction` is
+    /// prepended ahead of the builder's other instructions.
+    pub fn with_compute_budget(
+        mut self,
+        compute_unit_limit: Option<u32>,
+        compute_unit_price: Option<u64>,
+    ) -> Self {
+        self.compute_unit_limit = compute_unit_limit;
+        self.compute_unit_price = compute_unit_price;
+        self
+    }
+
+    /// compile, sign, and submit the accumulated instructions as a single transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics when signing or signature verification fails.
+    pub fn build_and_send(
+        self,
+        connection: &RpcClient,
+        payer: &dyn Signer,
+        signers: &[&dyn Signer],
+    ) -> Result<Signature, Box<dyn std::error::Error>> {
+        // prepend compute budget instructions, if requested, ahead of the rest
+        let mut remaining_instructions = self.instructions.into_iter();
+        let mut instructions = match remaining_instructions.next() {
+            Some(first) => {
+                prepend_compute_budget(first, self.compute_unit_limit, self.compute_unit_price)
+            }
+            None => Vec::new(),
+        };
+        instructions.extend(remaining_instructions);
+
+        let blockhash = connection.get_latest_blockhash()?;
+
+        if self.lookup_tables.is_empty() {
+            // no lookup tables: build and submit a legacy transaction
+            let msg = Message::new_with_blockhash(&instructions, Some(&payer.pubkey()), &blockhash);
+            let mut tx = Transaction::new_unsigned(msg);
+            tx.sign(signers, tx.message.recent_blockhash);
+            Ok(connection.send_and_confirm_transaction(&tx)?)
+        } else {
+            // lookup tables supplied: compile a versioned V0 message instead
+            let lookup_table_accounts = self
+                .lookup_tables
+                .iter()
+                .map(|table_pk| {
+                    let lookup_table_account = connection.get_account(table_pk)?;
+                    let address_lookup_table =
+                        AddressLookupTable::deserialize(&lookup_table_account.data)?;
+                    Ok::<_, Box<dyn std::error::Error>>(AddressLookupTableAccount {
+                        key: *table_pk,
+                        addresses: address_lookup_table.addresses.to_vec(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let tx = VersionedTransaction::try_new(
+                VersionedMessage::V0(v0::Message::try_compile(
+                    &payer.pubkey(),
+                    &instructions,
+                    &lookup_table_accounts,
+                    blockhash,
+                )?),
+                signers,
+            )?;
+
+            // serialize and encode transaction
+            let serialized_tx = bincode::serialize(&tx)?;
+            let serialized_encoded_tx = general_purpose::STANDARD.encode(serialized_tx);
+
+            // construct transaction pre-execution configuration
+            let config = RpcSendTransactionConfig {
+                skip_preflight: false,
+                preflight_commitment: Some(CommitmentLevel::Confirmed),
+                encoding: Some(UiTransactionEncoding::Base64),
+                ..RpcSendTransactionConfig::default()
+            };
+
+            // submit transaction and retrieve transaction signature
+            let signature = connection.send::<String>(
+                RpcRequest::SendTransaction,
+                serde_json::json!([serialized_encoded_tx, config]),
+            )?;
+
+            // verify transaction execution
+            connection.confirm_transaction_with_commitment(
+                &Signature::from_str(signature.as_str())?,
+                CommitmentConfig::finalized(),
+            )?;
+
+            Ok(Signature::from_str(&signature)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(BorshSerialize)]
+    pub struct UpdateBlob {
+        pub data: Vec<u8>,
+    }
+
+    #[test]
+    fn test_add_anchor_instruction_appends_instruction() {
+        let program_id = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+
+        let builder = TransactionBuilder::new().add_anchor_instruction(
+            &program_id,
+            "update_blob",
+            UpdateBlob {
+                data: "data".as_bytes().to_vec(),
+            },
+            vec![AccountMeta::new(account, false)],
+        );
+
+        assert_eq!(builder.instructions.len(), 1);
+        assert_eq!(builder.instructions[0].program_id, program_id);
+    }
+
+    #[test]
+    fn test_add_raw_instruction_appends_instruction() {
+        let ix = Instruction::new_with_bytes(Pubkey::new_unique(), &[], vec![]);
+
+        let builder = TransactionBuilder::new().add_raw_instruction(ix.clone());
+
+        assert_eq!(builder.instructions, vec![ix]);
+    }
+
+    #[test]
+    fn test_with_lookup_tables_sets_tables() {
+        let table_pk = Pubkey::new_unique();
+
+        let builder = TransactionBuilder::new().with_lookup_tables(vec![table_pk]);
+
+        assert_eq!(builder.lookup_tables, vec![table_pk]);
+    }
+
+    #[test]
+    fn test_with_compute_budget_sets_limit_and_price() {
+        let builder = TransactionBuilder::new().with_compute_budget(Some(200_000), Some(1));
+
+        assert_eq!(builder.compute_unit_limit, Some(200_000));
+        assert_eq!(builder.compute_unit_price, Some(1));
+    }
+}
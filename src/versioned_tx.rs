@@ -12,6 +12,7 @@ pub use solana_sdk::instruction::AccountMeta;
 pub use solana_sdk::{
     address_lookup_table::AddressLookupTableAccount,
     commitment_config::{CommitmentConfig, CommitmentLevel},
+    compute_budget::ComputeBudgetInstruction,
     instruction::Instruction,
     message::VersionedMessage,
     pubkey::Pubkey,
@@ -22,7 +23,7 @@ pub use solana_sdk::{
 };
 pub use solana_transaction_status::UiTransactionEncoding;
 
-use crate::legacy_tx::get_discriminant;
+use crate::legacy_tx::{get_discriminant, prepend_compute_budget};
 
 /// Sign and submit a legacy transaction.
 ///
@@ -54,7 +55,7 @@ use crate::legacy_tx::get_discriminant;
 ///         data: "another data".as_bytes().to_vec(),
 ///     };
 ///     let payer: Keypair = Keypair::read_from_file("~/.config/solana/id.json").unwrap();
-///     let signers = &[&payer];
+///     let signers: &[&dyn Signer] = &[&payer];
 ///
 ///     // create lookup table
 ///     let latest_blockhash = connection
@@ -90,6 +91,8 @@ use crate::legacy_tx::get_discriminant;
 ///         &payer,
 ///         signers,
 ///         accounts,
+///         None,
+///         None,
 ///     )
 ///     .unwrap();
 /// }
@@ -100,9 +103,11 @@ pub fn call_with_lookup_table<T>(
     instruction_name: &str,
     instruction_data: T,
     lookup_table_key: &Pubkey,
-    payer: &Keypair,
-    signers: &[&Keypair],
+    payer: &dyn Signer,
+    signers: &[&dyn Signer],
     accounts: Vec<AccountMeta>,
+    compute_unit_limit: Option<u32>,
+    compute_unit_price: Option<u64>,
 ) -> Result<Signature, Box<dyn std::error::Error>>
 where
     T: BorshSerialize,
@@ -123,12 +128,15 @@ where
         accounts,
     );
 
+    // prepend compute budget instructions, if requested, ahead of the program instruction
+    let instructions = prepend_compute_budget(ix, compute_unit_limit, compute_unit_price);
+
     // create versioned transaction with lookup table
     let blockhash = connection.get_latest_blockhash()?;
     let tx = VersionedTransaction::try_new(
         VersionedMessage::V0(solana_sdk::message::v0::Message::try_compile(
             &payer.pubkey(),
-            &[ix],
+            &instructions,
             &[address_lookup_table_account],
             blockhash,
         )?),
@@ -192,7 +200,7 @@ where
 /// ```
 pub fn create_lookup_table(
     connection: &RpcClient,
-    payer: &Keypair,
+    payer: &dyn Signer,
     latest_blockhash: solana_sdk::hash::Hash,
 ) -> Result<Pubkey, Box<dyn std::error::Error>> {
     let recent_slot = connection.get_slot()?;
@@ -206,7 +214,7 @@ pub fn create_lookup_table(
     connection.send_and_confirm_transaction(&Transaction::new_signed_with_payer(
         &[create_ix],
         Some(&payer.pubkey()),
-        &[&payer],
+        &[payer],
         latest_blockhash,
     ))?;
 
@@ -252,7 +260,7 @@ pub fn create_lookup_table(
 /// ```
 pub fn extend_lookup_table(
     connection: &RpcClient,
-    payer: &Keypair,
+    payer: &dyn Signer,
     latest_blockhash: solana_sdk::hash::Hash,
     table_pk: Pubkey,
     new_accounts: Vec<Pubkey>,
@@ -269,7 +277,7 @@ pub fn extend_lookup_table(
         connection.send_and_confirm_transaction(&Transaction::new_signed_with_payer(
             &[extend_ix],
             Some(&payer.pubkey()),
-            &[&payer],
+            &[payer],
             latest_blockhash,
         ))?;
 
@@ -282,6 +290,156 @@ pub fn extend_lookup_table(
         .is_ok())
 }
 
+/// Number of slots that must elapse after deactivation before a lookup table can be closed.
+///
+/// The address lookup table program only allows a table to be closed once
+/// its deactivation slot has aged out of the slot hashes sysvar, so this
+/// mirrors [`solana_sdk::slot_hashes::MAX_ENTRIES`].
+pub const LOOKUP_TABLE_COOLDOWN_SLOTS: u64 = solana_sdk::slot_hashes::MAX_ENTRIES as u64;
+
+/// deactivate a lookup table, starting its cool-down period.
+///
+/// This method submits a transaction that deactivates a lookup table.
+/// The table's accounts remain usable until deactivation, but the table
+/// itself cannot be extended again and must wait out
+/// [`LOOKUP_TABLE_COOLDOWN_SLOTS`] before it can be closed with
+/// [`close_lookup_table`].
+///
+/// # Panics
+///
+/// Panics when signature verification fails.
+///
+/// # Examples
+///
+/// This example uses the [`solana_program_client`] crate.
+///
+/// ```
+/// use solana_program_client::versioned_tx::*;
+///
+/// fn test_deactivate_lookup_table() {
+///     let connection = RpcClient::new("https://api.devnet.solana.com");
+///     let payer: Keypair =
+///         Keypair::read_from_file("/Users/cenwadike/.config/solana/solfate-dev.json").unwrap();
+///
+///     let latest_blockhash = connection
+///         .get_latest_blockhash()
+///         .expect("latest block hash");
+///
+///     let table_pk = create_lookup_table(&connection, &payer, latest_blockhash).unwrap();
+///     let _ = deactivate_lookup_table(&connection, &payer, &payer, &table_pk).unwrap();
+/// }
+/// ```
+pub fn deactivate_lookup_table(
+    connection: &RpcClient,
+    payer: &dyn Signer,
+    authority: &dyn Signer,
+    table_pk: &Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let deactivate_ix = solana_address_lookup_table_program::instruction::deactivate_lookup_table(
+        *table_pk,
+        authority.pubkey(),
+    );
+
+    let blockhash = connection.get_latest_blockhash()?;
+    let signature = connection.send_and_confirm_transaction(&Transaction::new_signed_with_payer(
+        &[deactivate_ix],
+        Some(&payer.pubkey()),
+        &[payer, authority],
+        blockhash,
+    ))?;
+
+    Ok(signature)
+}
+
+/// close a deactivated lookup table and reclaim its rent to `recipient`.
+///
+/// This method submits a transaction that closes a lookup table. Since the
+/// address lookup table program rejects a close before the table's
+/// cool-down period has elapsed, this checks the table's deactivation slot
+/// against [`LOOKUP_TABLE_COOLDOWN_SLOTS`] up front and returns a clear
+/// error instead of bubbling the raw RPC failure.
+///
+/// # Panics
+///
+/// Panics when signature verification fails.
+///
+/// # Examples
+///
+/// This example uses the [`solana_program_client`] crate.
+///
+/// ```
+/// use solana_program_client::versioned_tx::*;
+///
+/// fn test_close_lookup_table() {
+///     let connection = RpcClient::new("https://api.devnet.solana.com");
+///     let payer: Keypair =
+///         Keypair::read_from_file("/Users/cenwadike/.config/solana/solfate-dev.json").unwrap();
+///
+///     let latest_blockhash = connection
+///         .get_latest_blockhash()
+///         .expect("latest block hash");
+///
+///     let table_pk = create_lookup_table(&connection, &payer, latest_blockhash).unwrap();
+///     deactivate_lookup_table(&connection, &payer, &payer, &table_pk).unwrap();
+///     // ... wait out the cool-down period ...
+///     let _ = close_lookup_table(&connection, &payer, &payer, &payer.pubkey(), &table_pk).unwrap();
+/// }
+/// ```
+pub fn close_lookup_table(
+    connection: &RpcClient,
+    payer: &dyn Signer,
+    authority: &dyn Signer,
+    recipient: &Pubkey,
+    table_pk: &Pubkey,
+) -> Result<Signature, Box<dyn std::error::Error>> {
+    let lookup_table_account = connection.get_account(table_pk)?;
+    let address_lookup_table = AddressLookupTable::deserialize(&lookup_table_account.data)?;
+
+    let current_slot = connection.get_slot()?;
+    check_lookup_table_cooldown(address_lookup_table.meta.deactivation_slot, current_slot)?;
+
+    let close_ix = solana_address_lookup_table_program::instruction::close_lookup_table(
+        *table_pk,
+        authority.pubkey(),
+        *recipient,
+    );
+
+    let blockhash = connection.get_latest_blockhash()?;
+    let signature = connection.send_and_confirm_transaction(&Transaction::new_signed_with_payer(
+        &[close_ix],
+        Some(&payer.pubkey()),
+        &[payer, authority],
+        blockhash,
+    ))?;
+
+    Ok(signature)
+}
+
+/// verify a lookup table's cool-down period has elapsed, ahead of closing it.
+///
+/// Pure helper behind [`close_lookup_table`]'s early-return, split out so the
+/// one invariant the request calls out - no close before the cool-down
+/// elapses - can be unit tested without a live RPC connection.
+fn check_lookup_table_cooldown(
+    deactivation_slot: u64,
+    current_slot: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if deactivation_slot == u64::MAX {
+        return Err("lookup table has not been deactivated; call deactivate_lookup_table first".into());
+    }
+
+    let eligible_slot = deactivation_slot.saturating_add(LOOKUP_TABLE_COOLDOWN_SLOTS);
+    if current_slot < eligible_slot {
+        return Err(format!(
+            "lookup table cool-down period has not elapsed: deactivated at slot {deactivation_slot}, \
+             current slot {current_slot}, must wait until slot {eligible_slot}"
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -339,7 +497,7 @@ mod test {
         let payer: Keypair =
             Keypair::read_from_file("/Users/cenwadike/.config/solana/solfate-dev.json").unwrap();
 
-        let signers = &[&payer];
+        let signers: &[&dyn Signer] = &[&payer];
         // create lookup table
         let latest_blockhash = connection
             .get_latest_blockhash()
@@ -373,8 +531,61 @@ mod test {
             &payer,
             signers,
             accounts,
+            None,
+            None,
         );
 
         assert!(res.is_ok());
     }
+
+    #[test]
+    fn test_call_with_lookup_table_prepends_compute_budget_before_program_ix() {
+        let program_id = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let instruction_discriminant = get_discriminant("global", "update_blob");
+        let ix = Instruction::new_with_borsh(
+            program_id,
+            &(
+                instruction_discriminant,
+                UpdateBlob {
+                    data: "data".as_bytes().to_vec(),
+                },
+            ),
+            vec![AccountMeta::new(account, false)],
+        );
+
+        let instructions = prepend_compute_budget(ix.clone(), Some(200_000), Some(1));
+
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000)
+        );
+        assert_eq!(
+            instructions[1],
+            ComputeBudgetInstruction::set_compute_unit_price(1)
+        );
+        assert_eq!(instructions[2], ix);
+    }
+
+    #[test]
+    fn test_check_lookup_table_cooldown_rejects_not_deactivated() {
+        let err = check_lookup_table_cooldown(u64::MAX, 0).unwrap_err();
+        assert!(err.to_string().contains("has not been deactivated"));
+    }
+
+    #[test]
+    fn test_check_lookup_table_cooldown_rejects_too_early() {
+        let deactivation_slot = 1_000;
+        let current_slot = deactivation_slot + LOOKUP_TABLE_COOLDOWN_SLOTS - 1;
+        let err = check_lookup_table_cooldown(deactivation_slot, current_slot).unwrap_err();
+        assert!(err.to_string().contains("cool-down period has not elapsed"));
+    }
+
+    #[test]
+    fn test_check_lookup_table_cooldown_allows_once_elapsed() {
+        let deactivation_slot = 1_000;
+        let current_slot = deactivation_slot + LOOKUP_TABLE_COOLDOWN_SLOTS;
+        assert!(check_lookup_table_cooldown(deactivation_slot, current_slot).is_ok());
+    }
 }